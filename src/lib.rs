@@ -71,6 +71,89 @@ pub mod future {
 pub mod stream {
     use super::*;
 
+    /// Stream for the [`poll_fn`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct PollFn<F> {
+        f: F,
+    }
+
+    impl<F> Unpin for PollFn<F> {}
+
+    /// Creates a new stream wrapping around a function returning [`Poll`].
+    ///
+    /// Polling the returned stream delegates to the wrapped function.
+    pub fn poll_fn<T, F>(f: F) -> PollFn<F>
+    where
+        F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+    {
+        PollFn { f }
+    }
+
+    impl<F> fmt::Debug for PollFn<F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PollFn").finish_non_exhaustive()
+        }
+    }
+
+    impl<T, F> Stream for PollFn<F>
+    where
+        F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+    {
+        type Item = T;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            (self.f)(cx)
+        }
+    }
+
+    /// Creates a stream that endlessly repeats calling a closure.
+    ///
+    /// Unlike [`unfold`], `repeat_with()` threads no state through the closure and
+    /// allocates no future per element: each `poll_next()` simply calls `repeater`
+    /// and immediately yields the result. The returned stream never returns
+    /// `Poll::Pending` and never ends; combine it with `take()` or `take_while()`
+    /// to bound it.
+    pub fn repeat_with<T, F>(repeater: F) -> RepeatWith<F>
+    where
+        F: FnMut() -> T,
+    {
+        RepeatWith { f: repeater }
+    }
+
+    /// Stream for the [`repeat_with`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RepeatWith<F> {
+        f: F,
+    }
+
+    impl<F> Unpin for RepeatWith<F> {}
+
+    impl<F> fmt::Debug for RepeatWith<F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("RepeatWith").finish()
+        }
+    }
+
+    impl<T, F> Stream for RepeatWith<F>
+    where
+        F: FnMut() -> T,
+    {
+        type Item = T;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<T>> {
+            Poll::Ready(Some((self.f)()))
+        }
+    }
+
+    impl<T, F> futures_core::stream::FusedStream for RepeatWith<F>
+    where
+        F: FnMut() -> T,
+    {
+        fn is_terminated(&self) -> bool {
+            false
+        }
+    }
+
     /// Creates a `Stream` from a seed and a closure returning a `Future`.
     ///
     /// This function is the dual for the `Stream::fold()` adapter: while
@@ -98,6 +181,7 @@ pub mod stream {
             f,
             state: Some(init),
             fut: None,
+            done: false,
         }
     }
 
@@ -107,6 +191,7 @@ pub mod stream {
         f: F,
         state: Option<T>,
         fut: Option<Fut>,
+        done: bool,
     }
 
     impl<T, F, Fut> fmt::Debug for Unfold<T, F, Fut>
@@ -118,6 +203,7 @@ pub mod stream {
             f.debug_struct("Unfold")
                 .field("state", &self.state)
                 .field("fut", &self.fut)
+                .field("done", &self.done)
                 .finish()
         }
     }
@@ -130,7 +216,11 @@ pub mod stream {
         type Item = Item;
 
         fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-            let mut this = unsafe { self.get_unchecked_mut() };
+            let this = unsafe { self.get_unchecked_mut() };
+
+            if this.done {
+                return Poll::Ready(None);
+            }
 
             if let Some(state) = this.state.take() {
                 this.fut = Some((this.f)(state));
@@ -150,8 +240,247 @@ pub mod stream {
                 this.state = Some(next_state);
                 Poll::Ready(Some(item))
             } else {
+                this.done = true;
                 Poll::Ready(None)
             }
         }
     }
+
+    impl<T, F, Fut, Item> futures_core::stream::FusedStream for Unfold<T, F, Fut>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Option<(Item, T)>>,
+    {
+        fn is_terminated(&self) -> bool {
+            self.done
+        }
+    }
+
+    /// Creates a `Stream` from a seed and a fallible closure returning a `Future`.
+    ///
+    /// This is the fallible counterpart to [`unfold`]: the provided closure is called
+    /// with the current state and returns a `Future` resolving to a
+    /// `Result<Option<(Item, T)>, E>`. On `Ok(Some((item, next)))` the stream yields
+    /// `Ok(item)` and keeps `next` as the new state. On `Ok(None)` the stream
+    /// finishes. On `Err(e)` the stream yields `Err(e)` exactly once and then
+    /// finishes on every subsequent poll.
+    pub fn try_unfold<T, F, Fut, Item, E>(init: T, f: F) -> TryUnfold<T, F, Fut>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<Option<(Item, T)>, E>>,
+    {
+        TryUnfold {
+            f,
+            state: Some(init),
+            fut: None,
+            done: false,
+        }
+    }
+
+    /// Stream for the [`try_unfold`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryUnfold<T, F, Fut> {
+        f: F,
+        state: Option<T>,
+        fut: Option<Fut>,
+        done: bool,
+    }
+
+    impl<T, F, Fut> fmt::Debug for TryUnfold<T, F, Fut>
+    where
+        T: fmt::Debug,
+        Fut: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TryUnfold")
+                .field("state", &self.state)
+                .field("fut", &self.fut)
+                .field("done", &self.done)
+                .finish()
+        }
+    }
+
+    impl<T, F, Fut, Item, E> Stream for TryUnfold<T, F, Fut>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<Option<(Item, T)>, E>>,
+    {
+        type Item = Result<Item, E>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = unsafe { self.get_unchecked_mut() };
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            if let Some(state) = this.state.take() {
+                this.fut = Some((this.f)(state));
+            }
+
+            let fut = unsafe {
+                Pin::new_unchecked(
+                    this.fut
+                        .as_mut()
+                        .expect("TryUnfold holds either a state or a future until it is done"),
+                )
+            };
+            let step = futures_core::ready!(fut.poll(cx));
+            this.fut = None;
+
+            match step {
+                Ok(Some((item, next_state))) => {
+                    this.state = Some(next_state);
+                    Poll::Ready(Some(Ok(item)))
+                }
+                Ok(None) => {
+                    this.done = true;
+                    Poll::Ready(None)
+                }
+                Err(e) => {
+                    this.done = true;
+                    Poll::Ready(Some(Err(e)))
+                }
+            }
+        }
+    }
+
+    impl<T, F, Fut, Item, E> futures_core::stream::FusedStream for TryUnfold<T, F, Fut>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<Option<(Item, T)>, E>>,
+    {
+        fn is_terminated(&self) -> bool {
+            self.done
+        }
+    }
+
+    /// Extension trait for the [`Stream`] trait.
+    pub trait StreamExt: Stream {
+        /// Maps each item of this stream to a new stream, then flattens the
+        /// results into a single stream.
+        ///
+        /// The inner stream produced by `f` is fully drained before the next
+        /// item is pulled from the outer stream.
+        fn flat_map<U, F>(self, f: F) -> FlatMap<Self, U, F>
+        where
+            Self: Sized,
+            F: FnMut(Self::Item) -> U,
+            U: Stream,
+        {
+            FlatMap {
+                stream: self,
+                inner: None,
+                f,
+            }
+        }
+    }
+
+    impl<S: Stream + ?Sized> StreamExt for S {}
+
+    /// Stream for the [`StreamExt::flat_map`] method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlatMap<S, U, F> {
+        stream: S,
+        inner: Option<U>,
+        f: F,
+    }
+
+    impl<S, U, F> fmt::Debug for FlatMap<S, U, F>
+    where
+        S: fmt::Debug,
+        U: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("FlatMap")
+                .field("stream", &self.stream)
+                .field("inner", &self.inner)
+                .finish()
+        }
+    }
+
+    impl<S, U, F> Stream for FlatMap<S, U, F>
+    where
+        S: Stream,
+        U: Stream,
+        F: FnMut(S::Item) -> U,
+    {
+        type Item = U::Item;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = unsafe { self.get_unchecked_mut() };
+
+            loop {
+                if let Some(inner) = &mut this.inner {
+                    let inner = unsafe { Pin::new_unchecked(inner) };
+                    match inner.poll_next(cx) {
+                        Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                        Poll::Ready(None) => this.inner = None,
+                        Poll::Pending => return Poll::Pending,
+                    }
+                    continue;
+                }
+
+                let stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+                match stream.poll_next(cx) {
+                    Poll::Ready(Some(item)) => this.inner = Some((this.f)(item)),
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+
+    /// Wraps a stream so that each poll surfaces whether it was ready or pending.
+    ///
+    /// Instead of parking when the inner stream is not ready, `poll_immediate()`
+    /// yields `Poll::Ready(Some(Poll::Pending))` right away, letting callers
+    /// implement non-blocking "try to make progress" loops and interleave
+    /// readiness checks across multiple streams. Once the inner stream ends,
+    /// `poll_immediate()` ends as well.
+    pub fn poll_immediate<S: Stream>(s: S) -> PollImmediate<S> {
+        PollImmediate { stream: Some(s) }
+    }
+
+    /// Stream for the [`poll_immediate`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct PollImmediate<S> {
+        stream: Option<S>,
+    }
+
+    impl<S: fmt::Debug> fmt::Debug for PollImmediate<S> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PollImmediate")
+                .field("stream", &self.stream)
+                .finish()
+        }
+    }
+
+    impl<S: Stream> Stream for PollImmediate<S> {
+        type Item = Poll<S::Item>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = unsafe { self.get_unchecked_mut() };
+
+            let stream = match &mut this.stream {
+                Some(stream) => unsafe { Pin::new_unchecked(stream) },
+                None => return Poll::Ready(None),
+            };
+
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(item)) => Poll::Ready(Some(Poll::Ready(item))),
+                Poll::Ready(None) => {
+                    this.stream = None;
+                    Poll::Ready(None)
+                }
+                Poll::Pending => Poll::Ready(Some(Poll::Pending)),
+            }
+        }
+    }
+
+    impl<S: Stream> futures_core::stream::FusedStream for PollImmediate<S> {
+        fn is_terminated(&self) -> bool {
+            self.stream.is_none()
+        }
+    }
 }
\ No newline at end of file